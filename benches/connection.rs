@@ -0,0 +1,50 @@
+// Exactly one of the `postgres`, `sqlite` or `mysql` features selects the
+// backend every "diesel" case in this suite runs against; Cargo.toml makes
+// `postgres` the default so the existing results stay comparable.
+use diesel::prelude::*;
+use std::env;
+
+#[cfg(feature = "postgres")]
+pub type TestConnection = diesel::pg::PgConnection;
+#[cfg(feature = "sqlite")]
+pub type TestConnection = diesel::sqlite::SqliteConnection;
+#[cfg(feature = "mysql")]
+pub type TestConnection = diesel::mysql::MysqlConnection;
+
+pub fn test_connection() -> TestConnection {
+    let database_url = env::var("DATABASE_URL").unwrap();
+    let conn = TestConnection::establish(&database_url).unwrap();
+    reset_tables(&conn);
+    conn
+}
+
+#[cfg(feature = "postgres")]
+fn reset_tables(conn: &TestConnection) {
+    conn.execute("DELETE FROM comments").unwrap();
+    conn.execute("DELETE FROM posts").unwrap();
+    conn.execute("DELETE FROM users").unwrap();
+    conn.execute("alter sequence users_id_seq RESTART WITH 1")
+        .unwrap();
+    conn.execute("alter sequence posts_id_seq RESTART WITH 1")
+        .unwrap();
+    conn.execute("alter sequence comments_id_seq RESTART WITH 1")
+        .unwrap();
+}
+
+#[cfg(feature = "sqlite")]
+fn reset_tables(conn: &TestConnection) {
+    conn.execute("DELETE FROM comments").unwrap();
+    conn.execute("DELETE FROM posts").unwrap();
+    conn.execute("DELETE FROM users").unwrap();
+    conn.execute("DELETE FROM sqlite_sequence WHERE name IN ('users', 'posts', 'comments')")
+        .unwrap();
+}
+
+#[cfg(feature = "mysql")]
+fn reset_tables(conn: &TestConnection) {
+    conn.execute("SET FOREIGN_KEY_CHECKS = 0").unwrap();
+    conn.execute("TRUNCATE TABLE comments").unwrap();
+    conn.execute("TRUNCATE TABLE posts").unwrap();
+    conn.execute("TRUNCATE TABLE users").unwrap();
+    conn.execute("SET FOREIGN_KEY_CHECKS = 1").unwrap();
+}
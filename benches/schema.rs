@@ -0,0 +1,78 @@
+table! {
+    users {
+        id -> Integer,
+        name -> Text,
+        hair_color -> Nullable<Text>,
+    }
+}
+
+table! {
+    posts {
+        id -> Integer,
+        user_id -> Integer,
+        title -> Text,
+        body -> Nullable<Text>,
+    }
+}
+
+table! {
+    comments {
+        id -> Integer,
+        post_id -> Integer,
+        body -> Nullable<Text>,
+    }
+}
+
+joinable!(posts -> users(user_id));
+joinable!(comments -> posts(post_id));
+allow_tables_to_appear_in_same_query!(users, posts);
+allow_tables_to_appear_in_same_query!(users, comments);
+allow_tables_to_appear_in_same_query!(posts, comments);
+
+use diesel::*;
+
+#[derive(Queryable, Identifiable)]
+pub struct User {
+    pub id: i32,
+    pub name: String,
+    pub hair_color: Option<String>,
+}
+
+#[derive(Insertable)]
+#[table_name = "users"]
+pub struct NewUser {
+    pub name: String,
+    pub hair_color: Option<String>,
+}
+
+#[derive(Queryable, Identifiable, Associations)]
+#[belongs_to(User)]
+pub struct Post {
+    pub id: i32,
+    pub user_id: i32,
+    pub title: String,
+    pub body: Option<String>,
+}
+
+#[derive(Insertable)]
+#[table_name = "posts"]
+pub struct NewPost<'a> {
+    pub user_id: i32,
+    pub title: String,
+    pub body: Option<&'a str>,
+}
+
+#[derive(Queryable, Identifiable, Associations)]
+#[belongs_to(Post)]
+pub struct Comment {
+    pub id: i32,
+    pub post_id: i32,
+    pub body: Option<String>,
+}
+
+#[derive(Insertable)]
+#[table_name = "comments"]
+pub struct NewComment<'a> {
+    pub post_id: i32,
+    pub body: Option<&'a str>,
+}